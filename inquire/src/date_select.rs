@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::{
+    date_utils::get_current_date,
+    error::InquireResult,
+    formatter::{DateFormatter, DateTimeFormatter, DEFAULT_DATE_FORMATTER, DEFAULT_DATE_TIME_FORMATTER},
+    prompts::dateselect::{DateOutput, DateSelectPrompt, TimeGranularity},
+    validator::DateValidator,
+    DateInfo,
+};
+
+pub const DEFAULT_DATE_SELECT_WEEK_START: Weekday = Weekday::Sun;
+
+/// Prompt that lets the user pick a date (or, depending on configuration, a
+/// date range or a set of dates) off of an interactive calendar.
+pub struct DateSelect<'a> {
+    pub message: &'a str,
+    pub starting_date: NaiveDate,
+    pub min_date: Option<NaiveDate>,
+    pub max_date: Option<NaiveDate>,
+    pub week_start: Weekday,
+    pub help_message: Option<&'a str>,
+    pub formatter: DateFormatter<'a>,
+    pub time_formatter: DateTimeFormatter<'a>,
+    pub validators: Vec<Box<dyn DateValidator>>,
+    pub marked_dates: Option<&'a HashMap<NaiveDate, DateInfo>>,
+
+    /// When enabled, the prompt collects a start and end date instead of a
+    /// single date. See `DateSelectConfig::range_mode`.
+    pub range_mode: bool,
+
+    /// When enabled, Space toggles days in/out of a selection set and Enter
+    /// submits the whole set. See `DateSelectConfig::multi_select`.
+    pub multi_select: bool,
+
+    /// When set, submitting a day moves into a time-of-day editing stage at
+    /// this granularity, yielding a `DateOutput::DateTime`. See
+    /// `DateSelectConfig::time_granularity`.
+    pub time_granularity: Option<TimeGranularity>,
+}
+
+impl<'a> DateSelect<'a> {
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_date: get_current_date(),
+            min_date: None,
+            max_date: None,
+            week_start: DEFAULT_DATE_SELECT_WEEK_START,
+            help_message: None,
+            formatter: DEFAULT_DATE_FORMATTER,
+            time_formatter: DEFAULT_DATE_TIME_FORMATTER,
+            validators: Vec::new(),
+            marked_dates: None,
+            range_mode: false,
+            multi_select: false,
+            time_granularity: None,
+        }
+    }
+
+    pub fn with_starting_date(mut self, starting_date: NaiveDate) -> Self {
+        self.starting_date = starting_date;
+        self
+    }
+
+    pub fn with_min_date(mut self, min_date: NaiveDate) -> Self {
+        self.min_date = Some(min_date);
+        self
+    }
+
+    pub fn with_max_date(mut self, max_date: NaiveDate) -> Self {
+        self.max_date = Some(max_date);
+        self
+    }
+
+    pub fn with_week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    pub fn with_help_message(mut self, help_message: &'a str) -> Self {
+        self.help_message = Some(help_message);
+        self
+    }
+
+    pub fn with_formatter(mut self, formatter: DateFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    pub fn with_time_formatter(mut self, time_formatter: DateTimeFormatter<'a>) -> Self {
+        self.time_formatter = time_formatter;
+        self
+    }
+
+    pub fn with_validator(mut self, validator: Box<dyn DateValidator>) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    pub fn with_marked_dates(mut self, marked_dates: &'a HashMap<NaiveDate, DateInfo>) -> Self {
+        self.marked_dates = Some(marked_dates);
+        self
+    }
+
+    /// Switches the prompt into range-selection mode: the first Enter/Space
+    /// anchors a start date and the second submits the end date, yielding a
+    /// `DateOutput::Range` normalized so `start <= end`.
+    pub fn with_range_mode(mut self, range_mode: bool) -> Self {
+        self.range_mode = range_mode;
+        self
+    }
+
+    /// Switches the prompt into multi-select mode: Space toggles the day
+    /// under the cursor in/out of the selection, Enter submits every toggled
+    /// date as a sorted `DateOutput::Multiple`.
+    pub fn with_multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Adds a time-of-day editing stage after the day is chosen, at the
+    /// given granularity, turning the prompt's answer into a `NaiveDateTime`.
+    pub fn with_time(mut self, granularity: TimeGranularity) -> Self {
+        self.time_granularity = Some(granularity);
+        self
+    }
+
+    pub fn prompt(self) -> InquireResult<DateOutput> {
+        let prompt = DateSelectPrompt::new(self)?;
+        crate::prompts::prompt::run(prompt)
+    }
+}
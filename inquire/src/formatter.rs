@@ -0,0 +1,8 @@
+use chrono::{NaiveDate, NaiveDateTime};
+
+pub type DateFormatter<'a> = &'a dyn Fn(NaiveDate) -> String;
+pub type DateTimeFormatter<'a> = &'a dyn Fn(NaiveDateTime) -> String;
+
+pub const DEFAULT_DATE_FORMATTER: DateFormatter = &|date| date.format("%m/%d/%Y").to_string();
+pub const DEFAULT_DATE_TIME_FORMATTER: DateTimeFormatter =
+    &|date_time| date_time.format("%m/%d/%Y %H:%M").to_string();
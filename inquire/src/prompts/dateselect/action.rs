@@ -0,0 +1,21 @@
+/// Set of actions handled by `DateSelectPrompt`, emitted by the key bindings
+/// of the calendar UI.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DateSelectPromptAction {
+    GoToPrevDay,
+    GoToNextDay,
+    GoToPrevWeek,
+    GoToNextWeek,
+    GoToPrevMonth,
+    GoToNextMonth,
+    GoToPrevYear,
+    GoToNextYear,
+    Delete,
+    ConfirmDelete,
+    CancelDelete,
+    ToggleSelection,
+    IncrementHour,
+    DecrementHour,
+    IncrementMinute,
+    DecrementMinute,
+}
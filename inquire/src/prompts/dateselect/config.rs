@@ -0,0 +1,49 @@
+use chrono::{NaiveDate, Weekday};
+
+use crate::DateSelect;
+
+/// Rendering and behavior configuration for `DateSelectPrompt`, derived from
+/// the public `DateSelect` builder.
+#[derive(Copy, Clone, Debug)]
+pub struct DateSelectConfig {
+    pub min_date: Option<NaiveDate>,
+    pub max_date: Option<NaiveDate>,
+    pub week_start: Weekday,
+
+    /// When enabled, the prompt collects a start and end date instead of a
+    /// single date: the first Enter/Space anchors the start, the second
+    /// submits the end.
+    pub range_mode: bool,
+
+    /// When enabled, Space toggles the day under the cursor in or out of the
+    /// selection set, and Enter submits the whole set as a `Vec<NaiveDate>`.
+    pub multi_select: bool,
+
+    /// When set, submitting a day moves into a time-of-day editing stage at
+    /// this granularity instead of finishing the prompt, yielding a
+    /// `NaiveDateTime` once that stage is submitted too.
+    pub time_granularity: Option<TimeGranularity>,
+}
+
+/// Granularity of the time-of-day stage of a `with_time` configured
+/// `DateSelect`, handed to the backend's `render_time_editor`. It does not
+/// change the step size: hour actions always move the time by one hour and
+/// minute actions by one minute, at either granularity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimeGranularity {
+    Hour,
+    Minute,
+}
+
+impl<'a> From<&DateSelect<'a>> for DateSelectConfig {
+    fn from(so: &DateSelect<'a>) -> Self {
+        Self {
+            min_date: so.min_date,
+            max_date: so.max_date,
+            week_start: so.week_start,
+            range_mode: so.range_mode,
+            multi_select: so.multi_select,
+            time_granularity: so.time_granularity,
+        }
+    }
+}
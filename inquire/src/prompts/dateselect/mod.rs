@@ -0,0 +1,9 @@
+mod action;
+mod config;
+mod output;
+mod prompt;
+
+pub use action::DateSelectPromptAction;
+pub use config::{DateSelectConfig, TimeGranularity};
+pub use output::DateOutput;
+pub use prompt::DateSelectPrompt;
@@ -0,0 +1,19 @@
+use chrono::{NaiveDate, NaiveDateTime};
+
+/// Answer produced by `DateSelectPrompt`. The variant depends on how the
+/// prompt was configured: a plain single-date selection, a `range_mode`
+/// selection carrying a normalized `(start, end)` pair with `start <= end`,
+/// a `multi_select` selection carrying every toggled date in order, or a
+/// `with_time` selection carrying the combined date and time-of-day.
+///
+/// BREAKING CHANGE: this used to be a plain `{ date, to_delete }` struct;
+/// existing callers matching on a struct shape (or reading `.date`/
+/// `.to_delete` directly) need to switch to `DateOutput::Single { date,
+/// to_delete }`. Ships with a major version bump.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DateOutput {
+    Single { date: NaiveDate, to_delete: bool },
+    Range { start: NaiveDate, end: NaiveDate },
+    Multiple(Vec<NaiveDate>),
+    DateTime(NaiveDateTime),
+}
@@ -1,23 +1,26 @@
 use std::{
-    cmp::{max, min},
-    collections::HashMap,
-    ops::Add,
+    cmp::min,
+    collections::{HashMap, HashSet},
 };
 
-use chrono::{Datelike, Duration, NaiveDate};
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 
 use crate::{
     date_utils::{get_current_date, get_month},
     error::InquireResult,
-    formatter::DateFormatter,
+    formatter::{DateFormatter, DateTimeFormatter},
     prompts::prompt::{ActionResult, Prompt},
     ui::date::DateSelectBackend,
     utils::marked_dates_contains,
     validator::{DateValidator, ErrorMessage, Validation},
-    DateInfo, DateOutput, DateSelect, InquireError,
+    DateInfo, DateSelect, InquireError,
 };
 
-use super::{action::DateSelectPromptAction, config::DateSelectConfig};
+use super::{
+    action::DateSelectPromptAction,
+    config::{DateSelectConfig, TimeGranularity},
+    output::DateOutput,
+};
 
 pub struct DateSelectPrompt<'a> {
     message: &'a str,
@@ -25,11 +28,15 @@ pub struct DateSelectPrompt<'a> {
     current_date: NaiveDate,
     help_message: Option<&'a str>,
     formatter: DateFormatter<'a>,
+    time_formatter: DateTimeFormatter<'a>,
     validators: Vec<Box<dyn DateValidator>>,
     marked_dates: Option<&'a HashMap<NaiveDate, DateInfo>>,
     error: Option<ErrorMessage>,
     deletion_requested: bool,
     to_delete: bool,
+    range_anchor: Option<NaiveDate>,
+    selected_dates: HashSet<NaiveDate>,
+    time: Option<NaiveTime>,
 }
 
 impl<'a> DateSelectPrompt<'a> {
@@ -49,20 +56,70 @@ impl<'a> DateSelectPrompt<'a> {
             }
         }
 
+        let enabled_mode_count = [so.range_mode, so.multi_select, so.time_granularity.is_some()]
+            .into_iter()
+            .filter(|enabled| *enabled)
+            .count();
+        if enabled_mode_count > 1 {
+            return Err(InquireError::InvalidConfiguration(
+                "range_mode, multi_select and with_time are mutually exclusive".into(),
+            ));
+        }
+
         Ok(Self {
             message: so.message,
             current_date: so.starting_date,
             config: (&so).into(),
             help_message: so.help_message,
             formatter: so.formatter,
+            time_formatter: so.time_formatter,
             validators: so.validators,
             marked_dates: so.marked_dates,
             error: None,
             deletion_requested: false,
             to_delete: false,
+            range_anchor: None,
+            selected_dates: HashSet::new(),
+            time: None,
         })
     }
 
+    fn shift_time(&mut self, minutes: i64) -> ActionResult {
+        let time = match self.time {
+            Some(time) => time,
+            None => return ActionResult::Clean,
+        };
+
+        let minutes_since_midnight = time.hour() as i64 * 60 + time.minute() as i64;
+        let mut new_minutes = (minutes_since_midnight + minutes) % (24 * 60);
+        if new_minutes < 0 {
+            new_minutes += 24 * 60;
+        }
+
+        let new_time = NaiveTime::from_hms_opt(
+            (new_minutes / 60) as u32,
+            (new_minutes % 60) as u32,
+            0,
+        )
+        .expect("new_minutes is always a valid time of day");
+
+        if self.time == Some(new_time) {
+            return ActionResult::Clean;
+        }
+
+        self.time = Some(new_time);
+        ActionResult::NeedsRedraw
+    }
+
+    fn toggle_selection(&mut self) -> ActionResult {
+        let current_date = self.current_date;
+        if !self.selected_dates.remove(&current_date) {
+            self.selected_dates.insert(current_date);
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
     fn request_deletion(&mut self) -> ActionResult {
         if marked_dates_contains(&self.current_date, self.marked_dates)
             && self
@@ -83,53 +140,77 @@ impl<'a> DateSelectPrompt<'a> {
         ActionResult::Clean
     }
 
-    fn shift_date(&mut self, duration: Duration) -> ActionResult {
-        self.update_date(self.current_date.add(duration))
+    fn shift_date(&mut self, days: i64) -> ActionResult {
+        let magnitude = Days::new(days.unsigned_abs());
+        let new_date = if days < 0 {
+            self.current_date.checked_sub_days(magnitude)
+        } else {
+            self.current_date.checked_add_days(magnitude)
+        };
+
+        match new_date {
+            Some(new_date) => self.update_date(new_date),
+            None => ActionResult::Clean,
+        }
     }
 
     fn shift_months(&mut self, qty: i32) -> ActionResult {
         let date = self.current_date;
 
-        let years = qty / 12;
-        let months = qty % 12;
+        let cur_month0 = date.month0() as i32;
+        let total = cur_month0 + qty;
+        let year_carry = total.div_euclid(12);
+        let new_month0 = total.rem_euclid(12);
 
-        let new_year = date.year() + years;
-        let cur_month = date.month0() as i32;
-        let mut new_month = (cur_month + months) % 12;
-        if new_month < 0 {
-            new_month += 12;
-        }
+        let new_year = date.year() + year_carry;
+        let new_month = new_month0 as u32 + 1;
 
-        let new_date = date
-            .with_month0(new_month as u32)
-            .and_then(|d| d.with_year(new_year));
+        let days_in_new_month = Self::days_in_month(new_year, new_month);
+        let new_day = min(date.day(), days_in_new_month);
 
-        if let Some(new_date) = new_date {
+        if let Some(new_date) = NaiveDate::from_ymd_opt(new_year, new_month, new_day) {
             self.update_date(new_date)
         } else {
             ActionResult::Clean
         }
     }
 
+    /// Number of days in the given month, found by stepping to the first day
+    /// of the following month and taking the day before it.
+    fn days_in_month(year: i32, month: u32) -> u32 {
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+
+        NaiveDate::from_ymd_opt(next_year, next_month, 1)
+            .and_then(|d| d.pred_opt())
+            .map(|d| d.day())
+            .unwrap_or(28)
+    }
+
     fn update_date(&mut self, new_date: NaiveDate) -> ActionResult {
+        let min_date = self.config.min_date.unwrap_or(NaiveDate::MIN);
+        let max_date = self.config.max_date.unwrap_or(NaiveDate::MAX);
+        let new_date = new_date.clamp(min_date, max_date);
+
         if self.current_date == new_date {
             return ActionResult::Clean;
         }
 
         self.current_date = new_date;
-        if let Some(min_date) = self.config.min_date {
-            self.current_date = max(self.current_date, min_date);
-        }
-        if let Some(max_date) = self.config.max_date {
-            self.current_date = min(self.current_date, max_date);
-        }
 
         ActionResult::NeedsRedraw
     }
 
     fn validate_current_answer(&self) -> InquireResult<Validation> {
+        self.validate_date(self.cur_answer())
+    }
+
+    fn validate_date(&self, date: NaiveDate) -> InquireResult<Validation> {
         for validator in &self.validators {
-            match validator.validate(self.cur_answer()) {
+            match validator.validate(date) {
                 Ok(Validation::Valid) => {}
                 Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
                 Err(err) => return Err(InquireError::Custom(err)),
@@ -142,6 +223,89 @@ impl<'a> DateSelectPrompt<'a> {
     fn cur_answer(&self) -> NaiveDate {
         self.current_date
     }
+
+    fn submit_range(&mut self) -> InquireResult<Option<DateOutput>> {
+        let anchor = match self.range_anchor {
+            None => {
+                return match self.validate_current_answer()? {
+                    Validation::Valid => {
+                        self.range_anchor = Some(self.cur_answer());
+                        Ok(None)
+                    }
+                    Validation::Invalid(msg) => {
+                        self.error = Some(msg);
+                        Ok(None)
+                    }
+                };
+            }
+            Some(anchor) => anchor,
+        };
+
+        let answer = match self.validate_current_answer()? {
+            Validation::Valid => {
+                let end = self.cur_answer();
+                let (start, end) = if anchor <= end {
+                    (anchor, end)
+                } else {
+                    (end, anchor)
+                };
+
+                Some(DateOutput::Range { start, end })
+            }
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn submit_multi(&mut self) -> InquireResult<Option<DateOutput>> {
+        if self.selected_dates.is_empty() {
+            self.error = Some(ErrorMessage::from(
+                "At least one date must be selected".to_owned(),
+            ));
+            return Ok(None);
+        }
+
+        let mut dates: Vec<NaiveDate> = self.selected_dates.iter().copied().collect();
+        dates.sort_unstable();
+
+        for &date in &dates {
+            match self.validate_date(date)? {
+                Validation::Valid => {}
+                Validation::Invalid(msg) => {
+                    self.error = Some(msg);
+                    return Ok(None);
+                }
+            }
+        }
+
+        Ok(Some(DateOutput::Multiple(dates)))
+    }
+
+    fn submit_date_time(&mut self) -> InquireResult<Option<DateOutput>> {
+        if let Some(time) = self.time {
+            return Ok(Some(DateOutput::DateTime(NaiveDateTime::new(
+                self.cur_answer(),
+                time,
+            ))));
+        }
+
+        let answer = match self.validate_current_answer()? {
+            Validation::Valid => {
+                self.time = Some(NaiveTime::from_hms_opt(0, 0, 0).expect("midnight is valid"));
+                None
+            }
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(answer)
+    }
 }
 
 impl<'a, B> Prompt<B> for DateSelectPrompt<'a>
@@ -157,7 +321,18 @@ where
     }
 
     fn format_answer(&self, answer: &DateOutput) -> String {
-        (self.formatter)(answer.date)
+        match answer {
+            DateOutput::Single { date, .. } => (self.formatter)(*date),
+            DateOutput::Range { start, end } => {
+                format!("{} - {}", (self.formatter)(*start), (self.formatter)(*end))
+            }
+            DateOutput::Multiple(dates) => dates
+                .iter()
+                .map(|date| (self.formatter)(*date))
+                .collect::<Vec<_>>()
+                .join(", "),
+            DateOutput::DateTime(date_time) => (self.time_formatter)(*date_time),
+        }
     }
 
     fn config(&self) -> &DateSelectConfig {
@@ -165,8 +340,18 @@ where
     }
 
     fn submit(&mut self) -> InquireResult<Option<DateOutput>> {
+        if self.config.range_mode {
+            return self.submit_range();
+        }
+        if self.config.multi_select {
+            return self.submit_multi();
+        }
+        if self.config.time_granularity.is_some() {
+            return self.submit_date_time();
+        }
+
         let answer = match self.validate_current_answer()? {
-            Validation::Valid => Some(DateOutput {
+            Validation::Valid => Some(DateOutput::Single {
                 date: self.cur_answer(),
                 to_delete: self.to_delete,
             }),
@@ -193,16 +378,46 @@ where
             }
         }
 
+        // Once the time-of-day stage has started, the calendar underneath is
+        // no longer shown, so date navigation/delete/toggle must not mutate
+        // it either — only the time fields are live.
+        if self.time.is_some() {
+            let result = match action {
+                DateSelectPromptAction::IncrementHour => self.shift_time(60),
+                DateSelectPromptAction::DecrementHour => self.shift_time(-60),
+                DateSelectPromptAction::IncrementMinute => self.shift_time(1),
+                DateSelectPromptAction::DecrementMinute => self.shift_time(-1),
+                _ => ActionResult::Clean,
+            };
+
+            return Ok(result);
+        }
+
         let result = match action {
-            DateSelectPromptAction::GoToPrevWeek => self.shift_date(Duration::weeks(-1)),
-            DateSelectPromptAction::GoToNextWeek => self.shift_date(Duration::weeks(1)),
-            DateSelectPromptAction::GoToPrevDay => self.shift_date(Duration::days(-1)),
-            DateSelectPromptAction::GoToNextDay => self.shift_date(Duration::days(1)),
+            DateSelectPromptAction::GoToPrevWeek => self.shift_date(-7),
+            DateSelectPromptAction::GoToNextWeek => self.shift_date(7),
+            DateSelectPromptAction::GoToPrevDay => self.shift_date(-1),
+            DateSelectPromptAction::GoToNextDay => self.shift_date(1),
             DateSelectPromptAction::GoToPrevYear => self.shift_months(-12),
             DateSelectPromptAction::GoToNextYear => self.shift_months(12),
             DateSelectPromptAction::GoToPrevMonth => self.shift_months(-1),
             DateSelectPromptAction::GoToNextMonth => self.shift_months(1),
             DateSelectPromptAction::Delete => self.request_deletion(),
+            DateSelectPromptAction::ToggleSelection if self.config.multi_select => {
+                self.toggle_selection()
+            }
+            DateSelectPromptAction::ToggleSelection if self.config.range_mode => {
+                if self.range_anchor.is_none() {
+                    // mirrors the first Enter: anchors the start date (or
+                    // reports a validation error) without submitting yet.
+                    self.submit_range()?;
+                    ActionResult::NeedsRedraw
+                } else {
+                    // the anchor is set, so this Space completes the range;
+                    // `submit()` re-runs `submit_range` to produce the answer.
+                    ActionResult::Submit
+                }
+            }
             _ => ActionResult::Clean,
         };
 
@@ -211,6 +426,8 @@ where
 
     fn render(&self, backend: &mut B) -> InquireResult<()> {
         let prompt = &self.message;
+        let mut selected_dates: Vec<NaiveDate> = self.selected_dates.iter().copied().collect();
+        selected_dates.sort_unstable();
 
         if let Some(err) = &self.error {
             backend.render_error_message(err)?;
@@ -218,16 +435,25 @@ where
 
         backend.render_calendar_prompt(prompt)?;
 
-        backend.render_calendar(
-            get_month(self.current_date.month()),
-            self.current_date.year(),
-            self.config.week_start,
-            get_current_date(),
-            self.current_date,
-            self.config.min_date,
-            self.config.max_date,
-            self.marked_dates,
-        )?;
+        match (self.time, self.config.time_granularity) {
+            (Some(time), Some(granularity)) => {
+                backend.render_time_editor(self.current_date, time, granularity)?;
+            }
+            _ => {
+                backend.render_calendar(
+                    get_month(self.current_date.month()),
+                    self.current_date.year(),
+                    self.config.week_start,
+                    get_current_date(),
+                    self.current_date,
+                    self.config.min_date,
+                    self.config.max_date,
+                    self.marked_dates,
+                    self.range_anchor,
+                    &selected_dates,
+                )?;
+            }
+        }
 
         if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
@@ -242,3 +468,245 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shift_months_clamps_feb29_to_feb28_on_non_leap_year() {
+        let so = DateSelect::new("when")
+            .with_starting_date(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+        let mut prompt = DateSelectPrompt::new(so).unwrap();
+
+        prompt.shift_months(12);
+
+        assert_eq!(
+            prompt.current_date,
+            NaiveDate::from_ymd_opt(2025, 2, 28).unwrap()
+        );
+    }
+
+    #[test]
+    fn shift_months_carries_year_boundary_forward() {
+        let so = DateSelect::new("when")
+            .with_starting_date(NaiveDate::from_ymd_opt(2024, 12, 15).unwrap());
+        let mut prompt = DateSelectPrompt::new(so).unwrap();
+
+        prompt.shift_months(1);
+
+        assert_eq!(
+            prompt.current_date,
+            NaiveDate::from_ymd_opt(2025, 1, 15).unwrap()
+        );
+    }
+
+    #[test]
+    fn shift_months_carries_year_boundary_backward() {
+        let so = DateSelect::new("when")
+            .with_starting_date(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap());
+        let mut prompt = DateSelectPrompt::new(so).unwrap();
+
+        prompt.shift_months(-1);
+
+        assert_eq!(
+            prompt.current_date,
+            NaiveDate::from_ymd_opt(2024, 12, 15).unwrap()
+        );
+    }
+
+    #[test]
+    fn shift_date_does_not_panic_at_naive_date_min() {
+        let so = DateSelect::new("when").with_starting_date(NaiveDate::MIN);
+        let mut prompt = DateSelectPrompt::new(so).unwrap();
+
+        let result = prompt.shift_date(-1);
+
+        assert!(matches!(result, ActionResult::Clean));
+        assert_eq!(prompt.current_date, NaiveDate::MIN);
+    }
+
+    #[test]
+    fn shift_date_does_not_panic_at_naive_date_max() {
+        let so = DateSelect::new("when").with_starting_date(NaiveDate::MAX);
+        let mut prompt = DateSelectPrompt::new(so).unwrap();
+
+        let result = prompt.shift_date(1);
+
+        assert!(matches!(result, ActionResult::Clean));
+        assert_eq!(prompt.current_date, NaiveDate::MAX);
+    }
+
+    #[test]
+    fn submit_range_normalizes_start_and_end() {
+        let so = DateSelect::new("when")
+            .with_starting_date(NaiveDate::from_ymd_opt(2024, 6, 10).unwrap())
+            .with_range_mode(true);
+        let mut prompt = DateSelectPrompt::new(so).unwrap();
+
+        let anchored = prompt.submit_range().unwrap();
+        assert!(anchored.is_none());
+
+        prompt.current_date = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let answer = prompt.submit_range().unwrap().unwrap();
+
+        assert_eq!(
+            answer,
+            DateOutput::Range {
+                start: NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+                end: NaiveDate::from_ymd_opt(2024, 6, 10).unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn toggle_selection_anchors_and_submits_range_mode() {
+        let so = DateSelect::new("when")
+            .with_starting_date(NaiveDate::from_ymd_opt(2024, 6, 10).unwrap())
+            .with_range_mode(true);
+        let mut prompt = DateSelectPrompt::new(so).unwrap();
+
+        let result =
+            Prompt::<NoopBackend>::handle(&mut prompt, DateSelectPromptAction::ToggleSelection)
+                .unwrap();
+        assert!(matches!(result, ActionResult::NeedsRedraw));
+        assert_eq!(
+            prompt.range_anchor,
+            Some(NaiveDate::from_ymd_opt(2024, 6, 10).unwrap())
+        );
+
+        prompt.current_date = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let result =
+            Prompt::<NoopBackend>::handle(&mut prompt, DateSelectPromptAction::ToggleSelection)
+                .unwrap();
+        assert!(matches!(result, ActionResult::Submit));
+    }
+
+    #[test]
+    fn new_rejects_more_than_one_selection_mode() {
+        let so = DateSelect::new("when")
+            .with_multi_select(true)
+            .with_time(TimeGranularity::Hour);
+
+        let result = DateSelectPrompt::new(so);
+
+        assert!(matches!(
+            result,
+            Err(InquireError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[test]
+    fn submit_multi_returns_sorted_toggled_dates() {
+        let so = DateSelect::new("when").with_multi_select(true);
+        let mut prompt = DateSelectPrompt::new(so).unwrap();
+
+        prompt.current_date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        prompt.toggle_selection();
+        prompt.current_date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        prompt.toggle_selection();
+        prompt.current_date = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        prompt.toggle_selection();
+        // toggling the same day again removes it from the selection
+        prompt.toggle_selection();
+
+        let answer = prompt.submit_multi().unwrap().unwrap();
+
+        assert_eq!(
+            answer,
+            DateOutput::Multiple(vec![
+                NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 3, 5).unwrap(),
+            ])
+        );
+    }
+
+    #[test]
+    fn submit_multi_rejects_empty_selection() {
+        let so = DateSelect::new("when").with_multi_select(true);
+        let mut prompt = DateSelectPrompt::new(so).unwrap();
+
+        let answer = prompt.submit_multi().unwrap();
+
+        assert!(answer.is_none());
+        assert!(prompt.error.is_some());
+    }
+
+    #[test]
+    fn shift_time_wraps_around_midnight() {
+        let so = DateSelect::new("when").with_time(TimeGranularity::Minute);
+        let mut prompt = DateSelectPrompt::new(so).unwrap();
+
+        // first Enter validates the day and moves into the time stage at 00:00
+        prompt.submit_date_time().unwrap();
+        assert_eq!(prompt.time, Some(NaiveTime::from_hms_opt(0, 0, 0).unwrap()));
+
+        prompt.shift_time(-1);
+        assert_eq!(prompt.time, Some(NaiveTime::from_hms_opt(23, 59, 0).unwrap()));
+
+        prompt.shift_time(1);
+        assert_eq!(prompt.time, Some(NaiveTime::from_hms_opt(0, 0, 0).unwrap()));
+    }
+
+    struct NoopBackend;
+
+    impl DateSelectBackend for NoopBackend {
+        fn render_calendar_prompt(&mut self, _prompt: &str) -> InquireResult<()> {
+            Ok(())
+        }
+
+        fn render_calendar(
+            &mut self,
+            _month: chrono::Month,
+            _year: i32,
+            _week_start: chrono::Weekday,
+            _today: NaiveDate,
+            _selected_date: NaiveDate,
+            _min_date: Option<NaiveDate>,
+            _max_date: Option<NaiveDate>,
+            _marked_dates: Option<&HashMap<NaiveDate, DateInfo>>,
+            _range_anchor: Option<NaiveDate>,
+            _selected_dates: &[NaiveDate],
+        ) -> InquireResult<()> {
+            Ok(())
+        }
+
+        fn render_time_editor(
+            &mut self,
+            _date: NaiveDate,
+            _time: NaiveTime,
+            _granularity: TimeGranularity,
+        ) -> InquireResult<()> {
+            Ok(())
+        }
+
+        fn render_error_message(&mut self, _error: &ErrorMessage) -> InquireResult<()> {
+            Ok(())
+        }
+
+        fn render_help_message(&mut self, _help: &str) -> InquireResult<()> {
+            Ok(())
+        }
+
+        fn render_selection_details(&mut self, _details: &str) -> InquireResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn date_navigation_is_inert_once_time_stage_has_started() {
+        let so = DateSelect::new("when")
+            .with_starting_date(NaiveDate::from_ymd_opt(2024, 6, 10).unwrap())
+            .with_time(TimeGranularity::Hour);
+        let mut prompt = DateSelectPrompt::new(so).unwrap();
+
+        prompt.submit_date_time().unwrap();
+        let result = Prompt::<NoopBackend>::handle(&mut prompt, DateSelectPromptAction::GoToNextDay);
+
+        assert!(matches!(result, Ok(ActionResult::Clean)));
+        assert_eq!(
+            prompt.current_date,
+            NaiveDate::from_ymd_opt(2024, 6, 10).unwrap()
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use chrono::{Month, NaiveDate, NaiveTime, Weekday};
+
+use crate::{
+    error::InquireResult, prompts::dateselect::TimeGranularity, validator::ErrorMessage, DateInfo,
+};
+
+/// Rendering surface used by `DateSelectPrompt`. Implemented by the terminal
+/// backend that knows how to draw a calendar grid.
+pub trait DateSelectBackend {
+    fn render_calendar_prompt(&mut self, prompt: &str) -> InquireResult<()>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn render_calendar(
+        &mut self,
+        month: Month,
+        year: i32,
+        week_start: Weekday,
+        today: NaiveDate,
+        selected_date: NaiveDate,
+        min_date: Option<NaiveDate>,
+        max_date: Option<NaiveDate>,
+        marked_dates: Option<&HashMap<NaiveDate, DateInfo>>,
+        range_anchor: Option<NaiveDate>,
+        selected_dates: &[NaiveDate],
+    ) -> InquireResult<()>;
+
+    fn render_time_editor(
+        &mut self,
+        date: NaiveDate,
+        time: NaiveTime,
+        granularity: TimeGranularity,
+    ) -> InquireResult<()>;
+
+    fn render_error_message(&mut self, error: &ErrorMessage) -> InquireResult<()>;
+
+    fn render_help_message(&mut self, help: &str) -> InquireResult<()>;
+
+    fn render_selection_details(&mut self, details: &str) -> InquireResult<()>;
+}